@@ -1,6 +1,9 @@
-use numpy::ndarray::{Array2, Array3};
-use numpy::{IntoPyArray, PyArray2, PyArray3, PyReadonlyArray2};
+use numpy::ndarray::{Array1, Array2, Array3, ArrayView1, ArrayView2};
+use numpy::{IntoPyArray, PyArray1, PyArray2, PyArray3, PyReadonlyArray1, PyReadonlyArray2, PyReadonlyArray3};
+use pyo3::exceptions::PyValueError;
 use pyo3::prelude::*;
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
 use rayon::prelude::*;
 
 /// Normalize a float32 depth map to uint8 [0, 255].
@@ -99,6 +102,25 @@ pub fn depth_to_colormap_jet<'py>(
     out.into_pyarray_bound(py)
 }
 
+/// Pinhole camera intrinsics shared by the back-projection helpers.
+#[derive(Clone, Copy)]
+struct Intrinsics {
+    fx: f32,
+    fy: f32,
+    cx: f32,
+    cy: f32,
+}
+
+/// Back-project a single pixel to a camera-space XYZ point.
+fn back_project(arr: &ArrayView2<f32>, i: usize, j: usize, intr: Intrinsics) -> [f32; 3] {
+    let z = arr[[i, j]];
+    [
+        (j as f32 - intr.cx) * z / intr.fx,
+        (i as f32 - intr.cy) * z / intr.fy,
+        z,
+    ]
+}
+
 /// Back-project a (H, W) depth map to (H*W, 3) XYZ point cloud.
 #[pyfunction]
 pub fn depth_to_pointcloud<'py>(
@@ -112,6 +134,7 @@ pub fn depth_to_pointcloud<'py>(
     let arr = depth.as_array();
     let (h, w) = arr.dim();
     let n = h * w;
+    let intr = Intrinsics { fx, fy, cx, cy };
 
     let mut out = Array2::<f32>::zeros((n, 3));
 
@@ -121,11 +144,336 @@ pub fn depth_to_pointcloud<'py>(
         .for_each(|(idx, mut point)| {
             let i = idx / w;
             let j = idx % w;
-            let z = arr[[i, j]];
-            point[0] = (j as f32 - cx) * z / fx;
-            point[1] = (i as f32 - cy) * z / fy;
-            point[2] = z;
+            let p = back_project(&arr, i, j, intr);
+            point[0] = p[0];
+            point[1] = p[1];
+            point[2] = p[2];
+        });
+
+    out.into_pyarray_bound(py)
+}
+
+/// The 8 candidate line directions used by the directional denoiser, in
+/// (dy, dx) step form, spanning 180 degrees.
+const CDEF_DIRECTIONS: [(i32, i32); 8] = [
+    (0, 1),
+    (1, 2),
+    (1, 1),
+    (2, 1),
+    (1, 0),
+    (2, -1),
+    (1, -1),
+    (1, -2),
+];
+
+/// CDEF-style constraining function: clamps a neighbor's contribution so it
+/// shrinks as `|d|` grows, reaching zero once `|d| >> shift` reaches
+/// `strength`, where `shift = max(0, damping - ilog2(strength))`.
+fn constrain(d: i32, strength: i32, damping: i32) -> i32 {
+    if strength == 0 || d == 0 {
+        return 0;
+    }
+    let shift = (damping - (strength as u32).ilog2() as i32).max(0);
+    let mag = strength - (d.abs() >> shift);
+    if mag <= 0 {
+        0
+    } else {
+        d.signum() * mag
+    }
+}
+
+/// Estimate the dominant edge direction at (i, j): for each of the 8
+/// candidate directions, sum values along 3 parallel lines offset
+/// perpendicular to the direction, then score the direction by the
+/// variance across those line sums. A high variance means the lines
+/// disagree strongly with their neighbors while staying smooth along
+/// themselves, i.e. an edge runs parallel to that direction.
+fn pick_direction(arr: &ArrayView2<f32>, i: usize, j: usize, h: usize, w: usize) -> usize {
+    let mut best_dir = 0usize;
+    let mut best_var = f32::NEG_INFINITY;
+
+    for (d, &(dy, dx)) in CDEF_DIRECTIONS.iter().enumerate() {
+        let (pdy, pdx) = (-dx, dy);
+        let mut sums = [0f32; 3];
+
+        for (k, offset) in (-1i32..=1).enumerate() {
+            let mut sum = 0f32;
+            for t in -2i32..=2 {
+                let yy = i as i32 + dy * t + pdy * offset;
+                let xx = j as i32 + dx * t + pdx * offset;
+                if yy >= 0 && (yy as usize) < h && xx >= 0 && (xx as usize) < w {
+                    sum += arr[[yy as usize, xx as usize]];
+                }
+            }
+            sums[k] = sum;
+        }
+
+        let mean = sums.iter().sum::<f32>() / 3.0;
+        let var = sums.iter().map(|s| (s - mean) * (s - mean)).sum::<f32>();
+        if var > best_var {
+            best_var = var;
+            best_dir = d;
+        }
+    }
+
+    best_dir
+}
+
+/// CDEF-style constrain parameters, bundled to keep `tap_contrib`'s
+/// argument count under control.
+#[derive(Clone, Copy)]
+struct ConstrainParams {
+    strength: i32,
+    damping: i32,
+}
+
+/// Contribution of the tap at (i, j), or 0 if it falls outside
+/// the map. Bounds are taken from `arr.dim()` rather than passed in.
+fn tap_contrib(arr: &ArrayView2<f32>, center: f32, i: i32, j: i32, params: ConstrainParams) -> i32 {
+    let (h, w) = arr.dim();
+    if i < 0 || j < 0 || i as usize >= h || j as usize >= w {
+        return 0;
+    }
+    let d = (arr[[i as usize, j as usize]] - center).round() as i32;
+    constrain(d, params.strength, params.damping)
+}
+
+/// CDEF-style edge-directed denoiser for a float32 depth map: estimates the
+/// dominant edge direction per pixel, then filters along that direction
+/// (plus 45-degree secondary taps) with a constraining function that lets
+/// strong edges survive while flat, noisy regions get smoothed.
+#[pyfunction]
+pub fn denoise_depth_directional<'py>(
+    py: Python<'py>,
+    depth: PyReadonlyArray2<'py, f32>,
+    strength: i32,
+    damping: i32,
+) -> Bound<'py, PyArray2<f32>> {
+    let arr = depth.as_array();
+    let (h, w) = arr.dim();
+    let mut out = Array2::<f32>::zeros((h, w));
+
+    out.axis_iter_mut(ndarray::Axis(0))
+        .into_par_iter()
+        .enumerate()
+        .for_each(|(i, mut row)| {
+            for j in 0..w {
+                let center = arr[[i, j]];
+
+                if strength == 0 {
+                    row[j] = center;
+                    continue;
+                }
+
+                let dir = pick_direction(&arr, i, j, h, w);
+                let (dy, dx) = CDEF_DIRECTIONS[dir];
+                let (sdy1, sdx1) = CDEF_DIRECTIONS[(dir + 2) % 8];
+                let (sdy2, sdx2) = CDEF_DIRECTIONS[(dir + 6) % 8];
+                let params = ConstrainParams { strength, damping };
+
+                let mut sum = 0i32;
+                for &sign in &[1i32, -1] {
+                    sum += 4 * tap_contrib(&arr, center, i as i32 + dy * sign, j as i32 + dx * sign, params);
+                    sum += 2 * tap_contrib(&arr, center, i as i32 + dy * 2 * sign, j as i32 + dx * 2 * sign, params);
+                    sum += 2 * tap_contrib(&arr, center, i as i32 + sdy1 * sign, j as i32 + sdx1 * sign, params);
+                    sum += 2 * tap_contrib(&arr, center, i as i32 + sdy2 * sign, j as i32 + sdx2 * sign, params);
+                    sum += tap_contrib(&arr, center, i as i32 + sdy1 * 2 * sign, j as i32 + sdx1 * 2 * sign, params);
+                    sum += tap_contrib(&arr, center, i as i32 + sdy2 * 2 * sign, j as i32 + sdx2 * 2 * sign, params);
+                }
+
+                row[j] = center + ((sum + 8) >> 4) as f32;
+            }
         });
 
     out.into_pyarray_bound(py)
 }
+
+/// Build a Walker alias table for `N` weights so each index can later be
+/// drawn in O(1) time proportional to its weight. Returns `(prob, alias)`
+/// where drawing index `i` uniformly then accepting `i` itself if
+/// `u < prob[i]` (else `alias[i]`) reproduces the weighted distribution.
+fn build_alias_table(weights: ArrayView1<f32>) -> (Vec<f32>, Vec<usize>) {
+    let n = weights.len();
+    let total: f32 = weights.iter().sum();
+
+    let mut scaled: Vec<f32> = weights.iter().map(|&w| w * n as f32 / total).collect();
+    let mut small: Vec<usize> = Vec::new();
+    let mut large: Vec<usize> = Vec::new();
+
+    for (i, &p) in scaled.iter().enumerate() {
+        if p < 1.0 {
+            small.push(i);
+        } else {
+            large.push(i);
+        }
+    }
+
+    let mut prob = vec![0f32; n];
+    let mut alias = vec![0usize; n];
+
+    while let (Some(s), Some(l)) = (small.pop(), large.pop()) {
+        prob[s] = scaled[s];
+        alias[s] = l;
+        scaled[l] -= 1.0 - scaled[s];
+        if scaled[l] < 1.0 {
+            small.push(l);
+        } else {
+            large.push(l);
+        }
+    }
+
+    // Leftovers are numerical-precision stragglers left in one stack; they
+    // are drawn outright (prob 1.0) rather than aliased.
+    for i in large.into_iter().chain(small) {
+        prob[i] = 1.0;
+    }
+
+    (prob, alias)
+}
+
+/// Draw `m` points from a (N, 3) point cloud with replacement, proportional
+/// to per-point `weights` (e.g. confidence or inverse-density), via
+/// Walker's alias method. Returns the sampled (M, 3) points plus the chosen
+/// source indices. O(N) to build the alias table, O(1) per draw.
+#[pyfunction]
+pub fn subsample_pointcloud<'py>(
+    py: Python<'py>,
+    points: PyReadonlyArray2<'py, f32>,
+    weights: PyReadonlyArray1<'py, f32>,
+    m: usize,
+    seed: u64,
+) -> PyResult<(Bound<'py, PyArray2<f32>>, Bound<'py, PyArray1<i64>>)> {
+    let pts = points.as_array();
+    let w = weights.as_array();
+    let n = pts.nrows();
+
+    if w.len() != n {
+        return Err(PyValueError::new_err(format!(
+            "weights has length {} but points has {} rows",
+            w.len(),
+            n
+        )));
+    }
+    if n == 0 && m > 0 {
+        return Err(PyValueError::new_err(
+            "cannot draw samples from an empty point cloud",
+        ));
+    }
+
+    let (prob, alias) = build_alias_table(w);
+
+    let mut rng = StdRng::seed_from_u64(seed);
+    let mut out_pts = Array2::<f32>::zeros((m, 3));
+    let mut out_idx = Array1::<i64>::zeros(m);
+
+    for k in 0..m {
+        let i = rng.gen_range(0..n);
+        let u: f32 = rng.gen();
+        let chosen = if u < prob[i] { i } else { alias[i] };
+
+        out_idx[k] = chosen as i64;
+        for c in 0..3 {
+            out_pts[[k, c]] = pts[[chosen, c]];
+        }
+    }
+
+    Ok((out_pts.into_pyarray_bound(py), out_idx.into_pyarray_bound(py)))
+}
+
+fn in_bounds(i: i32, j: i32, h: usize, w: usize) -> Option<(usize, usize)> {
+    if i >= 0 && j >= 0 && (i as usize) < h && (j as usize) < w {
+        Some((i as usize, j as usize))
+    } else {
+        None
+    }
+}
+
+fn sub3(a: [f32; 3], b: [f32; 3]) -> [f32; 3] {
+    [a[0] - b[0], a[1] - b[1], a[2] - b[2]]
+}
+
+fn cross3(a: [f32; 3], b: [f32; 3]) -> [f32; 3] {
+    [
+        a[1] * b[2] - a[2] * b[1],
+        a[2] * b[0] - a[0] * b[2],
+        a[0] * b[1] - a[1] * b[0],
+    ]
+}
+
+/// Tangent vector at (i, j) along the (di, dj) axis: a centered difference
+/// `P(+1) - P(-1)` when both neighbors are in-bounds with valid depth,
+/// falling back to a forward or backward difference against the center
+/// pixel at borders. `None` if no valid neighbor is available. Bounds are
+/// taken from `arr.dim()` rather than passed in.
+fn directional_tangent(arr: &ArrayView2<f32>, i: usize, j: usize, di: i32, dj: i32, intr: Intrinsics) -> Option<[f32; 3]> {
+    let (h, w) = arr.dim();
+    let prev = in_bounds(i as i32 - di, j as i32 - dj, h, w).filter(|&(pi, pj)| arr[[pi, pj]] > 0.0);
+    let next = in_bounds(i as i32 + di, j as i32 + dj, h, w).filter(|&(ni, nj)| arr[[ni, nj]] > 0.0);
+
+    match (prev, next) {
+        (Some((pi, pj)), Some((ni, nj))) => Some(sub3(
+            back_project(arr, ni, nj, intr),
+            back_project(arr, pi, pj, intr),
+        )),
+        (Some((pi, pj)), None) => Some(sub3(back_project(arr, i, j, intr), back_project(arr, pi, pj, intr))),
+        (None, Some((ni, nj))) => Some(sub3(back_project(arr, ni, nj, intr), back_project(arr, i, j, intr))),
+        (None, None) => None,
+    }
+}
+
+/// Estimate per-pixel surface normals from a (H, W) depth map: back-project
+/// the horizontal and vertical neighbors of each pixel (reusing the
+/// `depth_to_pointcloud` projection formula), form the horizontal and
+/// vertical tangent vectors from their differences, take the cross product
+/// of the two tangents, and normalize. Zero-length or invalid-depth neighborhoods produce (0, 0, 0).
+#[pyfunction]
+pub fn depth_to_normals<'py>(
+    py: Python<'py>,
+    depth: PyReadonlyArray2<'py, f32>,
+    fx: f32,
+    fy: f32,
+    cx: f32,
+    cy: f32,
+) -> Bound<'py, PyArray3<f32>> {
+    let arr = depth.as_array();
+    let (h, w) = arr.dim();
+    let intr = Intrinsics { fx, fy, cx, cy };
+    let mut out = Array3::<f32>::zeros((h, w, 3));
+
+    out.axis_iter_mut(ndarray::Axis(0))
+        .into_par_iter()
+        .enumerate()
+        .for_each(|(i, mut row)| {
+            for j in 0..w {
+                if arr[[i, j]] <= 0.0 {
+                    continue;
+                }
+
+                let tx = directional_tangent(&arr, i, j, 0, 1, intr);
+                let ty = directional_tangent(&arr, i, j, 1, 0, intr);
+
+                let (Some(tx), Some(ty)) = (tx, ty) else {
+                    continue;
+                };
+
+                let n = cross3(tx, ty);
+                let len = (n[0] * n[0] + n[1] * n[1] + n[2] * n[2]).sqrt();
+                if len > 1e-9 {
+                    row[[j, 0]] = n[0] / len;
+                    row[[j, 1]] = n[1] / len;
+                    row[[j, 2]] = n[2] / len;
+                }
+            }
+        });
+
+    out.into_pyarray_bound(py)
+}
+
+/// Map a (H, W, 3) float32 normal map with components in [-1, 1] to a
+/// (H, W, 3) uint8 RGB image for direct visualization.
+#[pyfunction]
+pub fn normals_to_rgb<'py>(py: Python<'py>, normals: PyReadonlyArray3<'py, f32>) -> Bound<'py, PyArray3<u8>> {
+    let arr = normals.as_array();
+    let out = arr.mapv(|v| (((v.clamp(-1.0, 1.0) + 1.0) * 0.5) * 255.0).clamp(0.0, 255.0) as u8);
+    out.into_pyarray_bound(py)
+}
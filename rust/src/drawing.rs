@@ -1,5 +1,6 @@
+use numpy::ndarray::Array3;
 use numpy::IntoPyArray;
-use numpy::{PyArray3, PyReadonlyArray2, PyReadonlyArray3};
+use numpy::{PyArray3, PyReadonlyArray1, PyReadonlyArray2, PyReadonlyArray3};
 use pyo3::prelude::*;
 
 /// Draw rectangles on a (H, W, 3) uint8 image. Returns a new array.
@@ -28,52 +29,286 @@ pub fn draw_bboxes_on_frame<'py>(
         let x2 = (b[[idx, 2]] as isize).clamp(0, w as isize - 1) as usize;
         let y2 = (b[[idx, 3]] as isize).clamp(0, h as isize - 1) as usize;
 
-        if x2 <= x1 || y2 <= y1 {
-            continue;
-        }
+        draw_box_edges(&mut out, x1, y1, x2, y2, color, thickness);
+    }
+
+    out.into_pyarray_bound(py)
+}
+
+/// Draw a single rectangle's border onto `out`. The corners must already lie
+/// inside `out` (both callers clamp them); nothing is drawn for an empty
+/// box. Shared by `draw_bboxes_on_frame` and `draw_labels`.
+fn draw_box_edges(out: &mut Array3<u8>, x1: usize, y1: usize, x2: usize, y2: usize, color: (u8, u8, u8), thickness: usize) {
+    let (h, w, _c) = out.dim();
+
+    if x2 <= x1 || y2 <= y1 {
+        return;
+    }
 
-        let pixel = [color.0, color.1, color.2];
+    let pixel = [color.0, color.1, color.2];
 
-        // Top edge
-        for dy in 0..thickness.min(y2 - y1) {
-            let y = y1 + dy;
+    // Top edge
+    for dy in 0..thickness.min(y2 - y1) {
+        let y = y1 + dy;
+        for x in x1..=x2 {
+            out[[y, x, 0]] = pixel[0];
+            out[[y, x, 1]] = pixel[1];
+            out[[y, x, 2]] = pixel[2];
+        }
+    }
+    // Bottom edge
+    for dy in 0..thickness.min(y2 - y1) {
+        let y = y2 - dy;
+        if y < h {
             for x in x1..=x2 {
                 out[[y, x, 0]] = pixel[0];
                 out[[y, x, 1]] = pixel[1];
                 out[[y, x, 2]] = pixel[2];
             }
         }
-        // Bottom edge
-        for dy in 0..thickness.min(y2 - y1) {
-            let y = y2 - dy;
-            if y < h {
-                for x in x1..=x2 {
-                    out[[y, x, 0]] = pixel[0];
-                    out[[y, x, 1]] = pixel[1];
-                    out[[y, x, 2]] = pixel[2];
-                }
-            }
+    }
+    // Left edge
+    for dx in 0..thickness.min(x2 - x1) {
+        let x = x1 + dx;
+        for y in y1..=y2 {
+            out[[y, x, 0]] = pixel[0];
+            out[[y, x, 1]] = pixel[1];
+            out[[y, x, 2]] = pixel[2];
         }
-        // Left edge
-        for dx in 0..thickness.min(x2 - x1) {
-            let x = x1 + dx;
+    }
+    // Right edge
+    for dx in 0..thickness.min(x2 - x1) {
+        let x = x2 - dx;
+        if x < w {
             for y in y1..=y2 {
                 out[[y, x, 0]] = pixel[0];
                 out[[y, x, 1]] = pixel[1];
                 out[[y, x, 2]] = pixel[2];
             }
         }
-        // Right edge
-        for dx in 0..thickness.min(x2 - x1) {
-            let x = x2 - dx;
-            if x < w {
-                for y in y1..=y2 {
-                    out[[y, x, 0]] = pixel[0];
-                    out[[y, x, 1]] = pixel[1];
-                    out[[y, x, 2]] = pixel[2];
+    }
+}
+
+/// 8x8 bitmap glyphs for printable ASCII 32..=126, one row byte per scanline
+/// (bit 0 = leftmost pixel). Index with `ch as u32 - 32`.
+const FONT8X8: [[u8; 8]; 95] = [
+    [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00], // ' '
+    [0x18, 0x3C, 0x3C, 0x18, 0x18, 0x00, 0x18, 0x00], // !
+    [0x36, 0x36, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00], // "
+    [0x36, 0x36, 0x7F, 0x36, 0x7F, 0x36, 0x36, 0x00], // #
+    [0x0C, 0x3E, 0x03, 0x1E, 0x30, 0x1F, 0x0C, 0x00], // $
+    [0x00, 0x63, 0x33, 0x18, 0x0C, 0x66, 0x63, 0x00], // %
+    [0x1C, 0x36, 0x1C, 0x6E, 0x3B, 0x33, 0x6E, 0x00], // &
+    [0x06, 0x06, 0x03, 0x00, 0x00, 0x00, 0x00, 0x00], // '
+    [0x18, 0x0C, 0x06, 0x06, 0x06, 0x0C, 0x18, 0x00], // (
+    [0x06, 0x0C, 0x18, 0x18, 0x18, 0x0C, 0x06, 0x00], // )
+    [0x00, 0x66, 0x3C, 0xFF, 0x3C, 0x66, 0x00, 0x00], // *
+    [0x00, 0x0C, 0x0C, 0x3F, 0x0C, 0x0C, 0x00, 0x00], // +
+    [0x00, 0x00, 0x00, 0x00, 0x00, 0x0C, 0x0C, 0x06], // ,
+    [0x00, 0x00, 0x00, 0x3F, 0x00, 0x00, 0x00, 0x00], // -
+    [0x00, 0x00, 0x00, 0x00, 0x00, 0x0C, 0x0C, 0x00], // .
+    [0x60, 0x30, 0x18, 0x0C, 0x06, 0x03, 0x01, 0x00], // /
+    [0x3E, 0x63, 0x73, 0x7B, 0x6F, 0x67, 0x3E, 0x00], // 0
+    [0x0C, 0x0E, 0x0C, 0x0C, 0x0C, 0x0C, 0x3F, 0x00], // 1
+    [0x1E, 0x33, 0x30, 0x1C, 0x06, 0x33, 0x3F, 0x00], // 2
+    [0x1E, 0x33, 0x30, 0x1C, 0x30, 0x33, 0x1E, 0x00], // 3
+    [0x38, 0x3C, 0x36, 0x33, 0x7F, 0x30, 0x78, 0x00], // 4
+    [0x3F, 0x03, 0x1F, 0x30, 0x30, 0x33, 0x1E, 0x00], // 5
+    [0x1C, 0x06, 0x03, 0x1F, 0x33, 0x33, 0x1E, 0x00], // 6
+    [0x3F, 0x33, 0x30, 0x18, 0x0C, 0x0C, 0x0C, 0x00], // 7
+    [0x1E, 0x33, 0x33, 0x1E, 0x33, 0x33, 0x1E, 0x00], // 8
+    [0x1E, 0x33, 0x33, 0x3E, 0x30, 0x18, 0x0E, 0x00], // 9
+    [0x00, 0x0C, 0x0C, 0x00, 0x00, 0x0C, 0x0C, 0x00], // :
+    [0x00, 0x0C, 0x0C, 0x00, 0x00, 0x0C, 0x0C, 0x06], // ;
+    [0x18, 0x0C, 0x06, 0x03, 0x06, 0x0C, 0x18, 0x00], // <
+    [0x00, 0x00, 0x3F, 0x00, 0x00, 0x3F, 0x00, 0x00], // =
+    [0x06, 0x0C, 0x18, 0x30, 0x18, 0x0C, 0x06, 0x00], // >
+    [0x1E, 0x33, 0x30, 0x18, 0x0C, 0x00, 0x0C, 0x00], // ?
+    [0x3E, 0x63, 0x7B, 0x7B, 0x7B, 0x03, 0x1E, 0x00], // @
+    [0x0C, 0x1E, 0x33, 0x33, 0x3F, 0x33, 0x33, 0x00], // A
+    [0x3F, 0x66, 0x66, 0x3E, 0x66, 0x66, 0x3F, 0x00], // B
+    [0x3C, 0x66, 0x03, 0x03, 0x03, 0x66, 0x3C, 0x00], // C
+    [0x1F, 0x36, 0x66, 0x66, 0x66, 0x36, 0x1F, 0x00], // D
+    [0x7F, 0x46, 0x16, 0x1E, 0x16, 0x46, 0x7F, 0x00], // E
+    [0x7F, 0x46, 0x16, 0x1E, 0x16, 0x06, 0x0F, 0x00], // F
+    [0x3C, 0x66, 0x03, 0x03, 0x73, 0x66, 0x7C, 0x00], // G
+    [0x33, 0x33, 0x33, 0x3F, 0x33, 0x33, 0x33, 0x00], // H
+    [0x1E, 0x0C, 0x0C, 0x0C, 0x0C, 0x0C, 0x1E, 0x00], // I
+    [0x78, 0x30, 0x30, 0x30, 0x33, 0x33, 0x1E, 0x00], // J
+    [0x67, 0x66, 0x36, 0x1E, 0x36, 0x66, 0x67, 0x00], // K
+    [0x0F, 0x06, 0x06, 0x06, 0x46, 0x66, 0x7F, 0x00], // L
+    [0x63, 0x77, 0x7F, 0x7F, 0x6B, 0x63, 0x63, 0x00], // M
+    [0x63, 0x67, 0x6F, 0x7B, 0x73, 0x63, 0x63, 0x00], // N
+    [0x1C, 0x36, 0x63, 0x63, 0x63, 0x36, 0x1C, 0x00], // O
+    [0x3F, 0x66, 0x66, 0x3E, 0x06, 0x06, 0x0F, 0x00], // P
+    [0x1E, 0x33, 0x33, 0x33, 0x3B, 0x1E, 0x38, 0x00], // Q
+    [0x3F, 0x66, 0x66, 0x3E, 0x36, 0x66, 0x67, 0x00], // R
+    [0x1E, 0x33, 0x07, 0x0E, 0x38, 0x33, 0x1E, 0x00], // S
+    [0x3F, 0x2D, 0x0C, 0x0C, 0x0C, 0x0C, 0x1E, 0x00], // T
+    [0x33, 0x33, 0x33, 0x33, 0x33, 0x33, 0x3F, 0x00], // U
+    [0x33, 0x33, 0x33, 0x33, 0x33, 0x1E, 0x0C, 0x00], // V
+    [0x63, 0x63, 0x63, 0x6B, 0x7F, 0x77, 0x63, 0x00], // W
+    [0x63, 0x63, 0x36, 0x1C, 0x1C, 0x36, 0x63, 0x00], // X
+    [0x33, 0x33, 0x33, 0x1E, 0x0C, 0x0C, 0x1E, 0x00], // Y
+    [0x7F, 0x63, 0x31, 0x18, 0x4C, 0x66, 0x7F, 0x00], // Z
+    [0x1E, 0x06, 0x06, 0x06, 0x06, 0x06, 0x1E, 0x00], // [
+    [0x03, 0x06, 0x0C, 0x18, 0x30, 0x60, 0x40, 0x00], // backslash
+    [0x1E, 0x18, 0x18, 0x18, 0x18, 0x18, 0x1E, 0x00], // ]
+    [0x08, 0x1C, 0x36, 0x63, 0x00, 0x00, 0x00, 0x00], // ^
+    [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xFF], // _
+    [0x0C, 0x0C, 0x18, 0x00, 0x00, 0x00, 0x00, 0x00], // `
+    [0x00, 0x00, 0x1E, 0x30, 0x3E, 0x33, 0x6E, 0x00], // a
+    [0x07, 0x06, 0x06, 0x3E, 0x66, 0x66, 0x3B, 0x00], // b
+    [0x00, 0x00, 0x1E, 0x33, 0x03, 0x33, 0x1E, 0x00], // c
+    [0x38, 0x30, 0x30, 0x3E, 0x33, 0x33, 0x6E, 0x00], // d
+    [0x00, 0x00, 0x1E, 0x33, 0x3F, 0x03, 0x1E, 0x00], // e
+    [0x1C, 0x36, 0x06, 0x0F, 0x06, 0x06, 0x0F, 0x00], // f
+    [0x00, 0x00, 0x6E, 0x33, 0x33, 0x3E, 0x30, 0x1F], // g
+    [0x07, 0x06, 0x36, 0x6E, 0x66, 0x66, 0x67, 0x00], // h
+    [0x0C, 0x00, 0x0E, 0x0C, 0x0C, 0x0C, 0x1E, 0x00], // i
+    [0x30, 0x00, 0x30, 0x30, 0x30, 0x33, 0x33, 0x1E], // j
+    [0x07, 0x06, 0x66, 0x36, 0x1E, 0x36, 0x67, 0x00], // k
+    [0x0E, 0x0C, 0x0C, 0x0C, 0x0C, 0x0C, 0x1E, 0x00], // l
+    [0x00, 0x00, 0x33, 0x7F, 0x7F, 0x6B, 0x63, 0x00], // m
+    [0x00, 0x00, 0x1F, 0x33, 0x33, 0x33, 0x33, 0x00], // n
+    [0x00, 0x00, 0x1E, 0x33, 0x33, 0x33, 0x1E, 0x00], // o
+    [0x00, 0x00, 0x3B, 0x66, 0x66, 0x3E, 0x06, 0x0F], // p
+    [0x00, 0x00, 0x6E, 0x33, 0x33, 0x3E, 0x30, 0x78], // q
+    [0x00, 0x00, 0x3B, 0x6E, 0x66, 0x06, 0x0F, 0x00], // r
+    [0x00, 0x00, 0x3E, 0x03, 0x1E, 0x30, 0x1F, 0x00], // s
+    [0x08, 0x0C, 0x3E, 0x0C, 0x0C, 0x2C, 0x18, 0x00], // t
+    [0x00, 0x00, 0x33, 0x33, 0x33, 0x33, 0x6E, 0x00], // u
+    [0x00, 0x00, 0x33, 0x33, 0x33, 0x1E, 0x0C, 0x00], // v
+    [0x00, 0x00, 0x63, 0x6B, 0x7F, 0x7F, 0x36, 0x00], // w
+    [0x00, 0x00, 0x63, 0x36, 0x1C, 0x36, 0x63, 0x00], // x
+    [0x00, 0x00, 0x33, 0x33, 0x33, 0x3E, 0x30, 0x1F], // y
+    [0x00, 0x00, 0x3F, 0x19, 0x0C, 0x26, 0x3F, 0x00], // z
+    [0x38, 0x0C, 0x0C, 0x07, 0x0C, 0x0C, 0x38, 0x00], // {
+    [0x18, 0x18, 0x18, 0x00, 0x18, 0x18, 0x18, 0x00], // |
+    [0x07, 0x0C, 0x0C, 0x38, 0x0C, 0x0C, 0x07, 0x00], // }
+    [0x6E, 0x3B, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00], // ~
+];
+
+/// Look up the glyph for a printable ASCII char, falling back to space for
+/// anything outside 32..=126.
+fn glyph_for(ch: char) -> &'static [u8; 8] {
+    let code = ch as u32;
+    if (32..=126).contains(&code) {
+        &FONT8X8[(code - 32) as usize]
+    } else {
+        &FONT8X8[0]
+    }
+}
+
+/// Blit `text` onto `out` starting at (x, y), `scale`x upscaled, clipping at
+/// the frame bounds.
+fn blit_text(out: &mut Array3<u8>, text: &str, x: usize, y: usize, color: (u8, u8, u8), scale: usize) {
+    let (h, w, _c) = out.dim();
+    let mut cursor_x = x;
+
+    for ch in text.chars() {
+        let glyph = glyph_for(ch);
+
+        for (row, bits) in glyph.iter().enumerate() {
+            for col in 0..8 {
+                if bits & (1 << col) == 0 {
+                    continue;
+                }
+                let block_y = y + row * scale;
+                let block_x = cursor_x + col * scale;
+
+                for dy in 0..scale {
+                    let py = block_y + dy;
+                    if py >= h {
+                        continue;
+                    }
+                    for dx in 0..scale {
+                        let px = block_x + dx;
+                        if px >= w {
+                            continue;
+                        }
+                        out[[py, px, 0]] = color.0;
+                        out[[py, px, 1]] = color.1;
+                        out[[py, px, 2]] = color.2;
+                    }
                 }
             }
         }
+
+        cursor_x += 8 * scale;
+    }
+}
+
+/// Draw `text` onto a (H, W, 3) uint8 frame at (x, y) using an embedded 8x8
+/// bitmap font, upscaled by `scale`. Returns a new array.
+#[pyfunction]
+#[pyo3(signature = (frame, text, x, y, color=(255, 255, 255), scale=2))]
+pub fn draw_text_on_frame<'py>(
+    py: Python<'py>,
+    frame: PyReadonlyArray3<'py, u8>,
+    text: &str,
+    x: usize,
+    y: usize,
+    color: (u8, u8, u8),
+    scale: usize,
+) -> Bound<'py, PyArray3<u8>> {
+    let mut out = frame.as_array().to_owned();
+    blit_text(&mut out, text, x, y, color, scale);
+    out.into_pyarray_bound(py)
+}
+
+/// Draw boxes with "label score" text burned in just above each box, on a
+/// filled background strip for legibility.
+///
+/// boxes: (N, 4) float32 [x1, y1, x2, y2]
+/// labels: class name per box
+/// scores: confidence per box
+/// color: background strip / box color (R, G, B)
+#[pyfunction]
+#[pyo3(signature = (frame, boxes, labels, scores, color=(0, 255, 0), scale=1))]
+pub fn draw_labels<'py>(
+    py: Python<'py>,
+    frame: PyReadonlyArray3<'py, u8>,
+    boxes: PyReadonlyArray2<'py, f32>,
+    labels: Vec<String>,
+    scores: PyReadonlyArray1<'py, f32>,
+    color: (u8, u8, u8),
+    scale: usize,
+) -> Bound<'py, PyArray3<u8>> {
+    let arr = frame.as_array();
+    let (h, w, _c) = arr.dim();
+    let mut out = arr.to_owned();
+    let b = boxes.as_array();
+    let s = scores.as_array();
+    let n = b.nrows();
+
+    let glyph_w = 8 * scale;
+    let glyph_h = 8 * scale;
+
+    for idx in 0..n {
+        let x1 = (b[[idx, 0]] as isize).clamp(0, w as isize - 1) as usize;
+        let y1 = (b[[idx, 1]] as isize).clamp(0, h as isize - 1) as usize;
+        let x2 = (b[[idx, 2]] as isize).clamp(0, w as isize - 1) as usize;
+        let y2 = (b[[idx, 3]] as isize).clamp(0, h as isize - 1) as usize;
+
+        draw_box_edges(&mut out, x1, y1, x2, y2, color, 2);
+
+        let label = labels.get(idx).map(String::as_str).unwrap_or("");
+        let score = s.get(idx).copied().unwrap_or(0.0);
+        let text = format!("{} {:.2}", label, score);
+
+        let strip_w = (text.chars().count() * glyph_w).min(w.saturating_sub(x1));
+        let strip_y = y1.saturating_sub(glyph_h);
+
+        for yy in strip_y..(strip_y + glyph_h).min(h) {
+            for xx in x1..(x1 + strip_w).min(w) {
+                out[[yy, xx, 0]] = color.0;
+                out[[yy, xx, 1]] = color.1;
+                out[[yy, xx, 2]] = color.2;
+            }
+        }
+
+        blit_text(&mut out, &text, x1, strip_y, (255, 255, 255), scale);
     }
 
     out.into_pyarray_bound(py)
@@ -1,5 +1,5 @@
-use numpy::ndarray::Array3;
-use numpy::{PyArray3, PyReadonlyArray3, IntoPyArray};
+use numpy::ndarray::{Array3, ArrayView3};
+use numpy::{PyArray3, PyReadonlyArray2, PyReadonlyArray3, IntoPyArray};
 use pyo3::prelude::*;
 use rayon::prelude::*;
 
@@ -32,6 +32,30 @@ pub fn rgb_to_bgr<'py>(py: Python<'py>, frame: PyReadonlyArray3<'py, u8>) -> Bou
     bgr_to_rgb(py, frame)
 }
 
+/// Bilinearly sample channel `k` of `arr` from the four integer neighbors
+/// `(y0, x0)`..`(y1, x1)` using fractional offsets `fx`/`fy`. Shared by
+/// `resize_bilinear` and `warp_perspective`.
+fn bilinear_sample(
+    arr: &ArrayView3<u8>,
+    y0: usize,
+    x0: usize,
+    y1: usize,
+    x1: usize,
+    fx: f32,
+    fy: f32,
+    k: usize,
+) -> u8 {
+    let tl = arr[[y0, x0, k]] as f32;
+    let tr = arr[[y0, x1, k]] as f32;
+    let bl = arr[[y1, x0, k]] as f32;
+    let br = arr[[y1, x1, k]] as f32;
+
+    let top = tl * (1.0 - fx) + tr * fx;
+    let bot = bl * (1.0 - fx) + br * fx;
+    let val = top * (1.0 - fy) + bot * fy;
+    val.clamp(0.0, 255.0) as u8
+}
+
 /// Resize a (H, W, 3) uint8 image using bilinear interpolation.
 #[pyfunction]
 pub fn resize_bilinear<'py>(
@@ -68,15 +92,7 @@ pub fn resize_bilinear<'py>(
                 let fx = (x_f - x0 as f64) as f32;
 
                 for k in 0..c {
-                    let tl = arr[[y0, x0, k]] as f32;
-                    let tr = arr[[y0, x1, k]] as f32;
-                    let bl = arr[[y1, x0, k]] as f32;
-                    let br = arr[[y1, x1, k]] as f32;
-
-                    let top = tl * (1.0 - fx) + tr * fx;
-                    let bot = bl * (1.0 - fx) + br * fx;
-                    let val = top * (1.0 - fy) + bot * fy;
-                    row[[j, k]] = val.clamp(0.0, 255.0) as u8;
+                    row[[j, k]] = bilinear_sample(&arr, y0, x0, y1, x1, fx, fy, k);
                 }
             }
         });
@@ -91,3 +107,160 @@ pub fn normalize_frame<'py>(py: Python<'py>, frame: PyReadonlyArray3<'py, u8>) -
     let out = arr.mapv(|v| v as f32 / 255.0);
     out.into_pyarray_bound(py)
 }
+
+/// Solve the 8x8 linear system for a homography mapping `src` onto `dst`
+/// (both length-4 point lists, TL/TR/BR/BL order) via Gaussian elimination
+/// with partial pivoting. Returns the row-major 3x3 matrix with h33 = 1.
+fn solve_homography(src: &[[f32; 2]; 4], dst: &[[f32; 2]; 4]) -> [[f64; 3]; 3] {
+    let mut a = [[0.0f64; 9]; 8];
+
+    for i in 0..4 {
+        let (x, y) = (src[i][0] as f64, src[i][1] as f64);
+        let (u, v) = (dst[i][0] as f64, dst[i][1] as f64);
+
+        a[2 * i] = [x, y, 1.0, 0.0, 0.0, 0.0, -u * x, -u * y, u];
+        a[2 * i + 1] = [0.0, 0.0, 0.0, x, y, 1.0, -v * x, -v * y, v];
+    }
+
+    // Gaussian elimination with partial pivoting.
+    for col in 0..8 {
+        let mut pivot = col;
+        for row in (col + 1)..8 {
+            if a[row][col].abs() > a[pivot][col].abs() {
+                pivot = row;
+            }
+        }
+        a.swap(col, pivot);
+
+        let diag = a[col][col];
+        if diag.abs() > 1e-12 {
+            for v in a[col].iter_mut().skip(col) {
+                *v /= diag;
+            }
+        }
+
+        let pivot_row = a[col];
+        for row in 0..8 {
+            if row == col {
+                continue;
+            }
+            let factor = a[row][col];
+            for (v, &p) in a[row].iter_mut().zip(pivot_row.iter()).skip(col) {
+                *v -= factor * p;
+            }
+        }
+    }
+
+    [
+        [a[0][8], a[1][8], a[2][8]],
+        [a[3][8], a[4][8], a[5][8]],
+        [a[6][8], a[7][8], 1.0],
+    ]
+}
+
+/// Invert a 3x3 matrix via the adjugate/determinant formula.
+fn invert_3x3(m: [[f64; 3]; 3]) -> [[f64; 3]; 3] {
+    let det = m[0][0] * (m[1][1] * m[2][2] - m[1][2] * m[2][1])
+        - m[0][1] * (m[1][0] * m[2][2] - m[1][2] * m[2][0])
+        + m[0][2] * (m[1][0] * m[2][1] - m[1][1] * m[2][0]);
+    let inv_det = 1.0 / det;
+
+    [
+        [
+            (m[1][1] * m[2][2] - m[1][2] * m[2][1]) * inv_det,
+            (m[0][2] * m[2][1] - m[0][1] * m[2][2]) * inv_det,
+            (m[0][1] * m[1][2] - m[0][2] * m[1][1]) * inv_det,
+        ],
+        [
+            (m[1][2] * m[2][0] - m[1][0] * m[2][2]) * inv_det,
+            (m[0][0] * m[2][2] - m[0][2] * m[2][0]) * inv_det,
+            (m[0][2] * m[1][0] - m[0][0] * m[1][2]) * inv_det,
+        ],
+        [
+            (m[1][0] * m[2][1] - m[1][1] * m[2][0]) * inv_det,
+            (m[0][1] * m[2][0] - m[0][0] * m[2][1]) * inv_det,
+            (m[0][0] * m[1][1] - m[0][1] * m[1][0]) * inv_det,
+        ],
+    ]
+}
+
+/// Rectify a tilted quadrilateral region of a (H, W, 3) uint8 frame to a
+/// fronto-parallel `out_h` x `out_w` rectangle via a perspective warp.
+///
+/// `src_pts`: (4, 2) float32 source corners in TL, TR, BR, BL order. These
+/// are mapped onto the destination rectangle corners (0,0), (out_w-1,0),
+/// (out_w-1,out_h-1), (0,out_h-1) to solve for the homography; each output
+/// pixel is then back-projected through the inverse homography and
+/// bilinearly sampled from the source. Output pixels that back-project
+/// outside the source frame are zero.
+#[pyfunction]
+pub fn warp_perspective<'py>(
+    py: Python<'py>,
+    frame: PyReadonlyArray3<'py, u8>,
+    src_pts: PyReadonlyArray2<'py, f32>,
+    out_h: usize,
+    out_w: usize,
+) -> Bound<'py, PyArray3<u8>> {
+    let arr = frame.as_array();
+    let (h, w, c) = arr.dim();
+
+    if out_h == 0 || out_w == 0 {
+        return Array3::<u8>::zeros((out_h, out_w, c)).into_pyarray_bound(py);
+    }
+
+    let p = src_pts.as_array();
+
+    let src = [
+        [p[[0, 0]], p[[0, 1]]],
+        [p[[1, 0]], p[[1, 1]]],
+        [p[[2, 0]], p[[2, 1]]],
+        [p[[3, 0]], p[[3, 1]]],
+    ];
+    let dst = [
+        [0.0, 0.0],
+        [(out_w - 1) as f32, 0.0],
+        [(out_w - 1) as f32, (out_h - 1) as f32],
+        [0.0, (out_h - 1) as f32],
+    ];
+
+    let homography = solve_homography(&src, &dst);
+    let inv = invert_3x3(homography);
+
+    let mut out = Array3::<u8>::zeros((out_h, out_w, c));
+
+    out.axis_iter_mut(ndarray::Axis(0))
+        .into_par_iter()
+        .enumerate()
+        .for_each(|(v, mut row)| {
+            for u in 0..out_w {
+                let (uf, vf) = (u as f64, v as f64);
+                let x = inv[0][0] * uf + inv[0][1] * vf + inv[0][2];
+                let y = inv[1][0] * uf + inv[1][1] * vf + inv[1][2];
+                let wc = inv[2][0] * uf + inv[2][1] * vf + inv[2][2];
+
+                if wc.abs() < 1e-12 {
+                    continue;
+                }
+
+                let src_x = x / wc;
+                let src_y = y / wc;
+
+                if src_x < 0.0 || src_y < 0.0 || src_x > (w - 1) as f64 || src_y > (h - 1) as f64 {
+                    continue;
+                }
+
+                let x0 = (src_x as usize).min(w.saturating_sub(2));
+                let y0 = (src_y as usize).min(h.saturating_sub(2));
+                let x1 = x0 + 1;
+                let y1 = y0 + 1;
+                let fx = (src_x - x0 as f64) as f32;
+                let fy = (src_y - y0 as f64) as f32;
+
+                for k in 0..c {
+                    row[[u, k]] = bilinear_sample(&arr, y0, x0, y1, x1, fx, fy, k);
+                }
+            }
+        });
+
+    out.into_pyarray_bound(py)
+}
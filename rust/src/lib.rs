@@ -14,6 +14,7 @@ fn _rust_core(m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_function(wrap_pyfunction!(image_ops::rgb_to_bgr, m)?)?;
     m.add_function(wrap_pyfunction!(image_ops::resize_bilinear, m)?)?;
     m.add_function(wrap_pyfunction!(image_ops::normalize_frame, m)?)?;
+    m.add_function(wrap_pyfunction!(image_ops::warp_perspective, m)?)?;
 
     // nms
     m.add_function(wrap_pyfunction!(nms::nms_boxes, m)?)?;
@@ -23,9 +24,15 @@ fn _rust_core(m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_function(wrap_pyfunction!(depth::normalize_depth_map, m)?)?;
     m.add_function(wrap_pyfunction!(depth::depth_to_colormap_jet, m)?)?;
     m.add_function(wrap_pyfunction!(depth::depth_to_pointcloud, m)?)?;
+    m.add_function(wrap_pyfunction!(depth::denoise_depth_directional, m)?)?;
+    m.add_function(wrap_pyfunction!(depth::subsample_pointcloud, m)?)?;
+    m.add_function(wrap_pyfunction!(depth::depth_to_normals, m)?)?;
+    m.add_function(wrap_pyfunction!(depth::normals_to_rgb, m)?)?;
 
     // drawing
     m.add_function(wrap_pyfunction!(drawing::draw_bboxes_on_frame, m)?)?;
+    m.add_function(wrap_pyfunction!(drawing::draw_text_on_frame, m)?)?;
+    m.add_function(wrap_pyfunction!(drawing::draw_labels, m)?)?;
 
     // frame
     m.add_function(wrap_pyfunction!(frame::generate_dummy_frame, m)?)?;